@@ -1,7 +1,11 @@
 use crate::DiagnosticId;
 use bevy_app::{App, Plugin};
+use bevy_ecs::system::Resource;
+use bevy_utils::Duration;
 
-/// Adds a System Information Diagnostic, specifically `cpu_usage` (in %) and `mem_usage` (in %)
+/// Adds a System Information Diagnostic, specifically `cpu_usage` (in %), `mem_usage` (in %),
+/// `total_mem`/`used_mem` (in GiB), and the current process' own `process_cpu_usage` (in %) and
+/// `process_mem_usage` (resident memory, in GiB)
 ///
 /// Supported targets:
 /// * linux,
@@ -10,12 +14,33 @@ use bevy_app::{App, Plugin};
 /// * macos
 ///
 /// NOT supported when using the `bevy/dynamic` feature even when using previously mentioned targets
-#[derive(Default)]
-pub struct SystemInformationDiagnosticsPlugin;
+pub struct SystemInformationDiagnosticsPlugin {
+    /// When `true`, also registers one `Diagnostic` per logical CPU (named `cpu_usage/core_N`)
+    /// instead of only the averaged `cpu_usage`. Off by default.
+    pub per_core: bool,
+    /// Minimum time between two `sysinfo` refreshes. `sysinfo` recommends waiting at least this
+    /// long between CPU refreshes for the readings to be meaningful, and it also keeps the
+    /// per-frame overhead of this plugin low on high-FPS apps. Defaults to 500ms.
+    pub refresh_interval: Duration,
+}
+
+impl Default for SystemInformationDiagnosticsPlugin {
+    fn default() -> Self {
+        Self {
+            per_core: false,
+            refresh_interval: Duration::from_millis(500),
+        }
+    }
+}
+
 impl Plugin for SystemInformationDiagnosticsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_startup_system(internal::setup_system)
-            .add_system(internal::diagnostic_system);
+        app.insert_resource(SystemInfoConfig {
+            per_core: self.per_core,
+            refresh_interval: self.refresh_interval,
+        })
+        .add_startup_system(internal::setup_system)
+        .add_system(internal::diagnostic_system);
     }
 }
 
@@ -24,6 +49,31 @@ impl SystemInformationDiagnosticsPlugin {
         DiagnosticId::from_u128(78494871623549551581510633532637320956);
     pub const MEM_USAGE: DiagnosticId =
         DiagnosticId::from_u128(42846254859293759601295317811892519825);
+    pub const PROCESS_CPU_USAGE: DiagnosticId =
+        DiagnosticId::from_u128(4265572714649696979983952949304209909);
+    pub const PROCESS_MEM_USAGE: DiagnosticId =
+        DiagnosticId::from_u128(626963369167404938173385123568204740);
+    pub const TOTAL_MEM: DiagnosticId =
+        DiagnosticId::from_u128(1116311245071134194682178253448203291);
+    pub const USED_MEM: DiagnosticId =
+        DiagnosticId::from_u128(4749688017841345384191953421457632908);
+
+    // Base seed that per-core `DiagnosticId`s are derived from (see `core_cpu_usage_diagnostic_id`).
+    const CORE_CPU_USAGE_BASE: u128 = 2510886364736576539389912386440058235;
+
+    /// Returns the stable `DiagnosticId` used for the `cpu_usage/core_N` diagnostic of the
+    /// logical CPU at `core_index`.
+    pub fn core_cpu_usage_diagnostic_id(core_index: usize) -> DiagnosticId {
+        DiagnosticId::from_u128(Self::CORE_CPU_USAGE_BASE + core_index as u128)
+    }
+}
+
+/// Resource holding the options a [`SystemInformationDiagnosticsPlugin`] was built with,
+/// threaded through to its systems.
+#[derive(Resource)]
+pub(crate) struct SystemInfoConfig {
+    pub(crate) per_core: bool,
+    pub(crate) refresh_interval: Duration,
 }
 
 // NOTE: sysinfo fails to compile when using bevy dynamic or on iOS and does nothing on wasm
@@ -37,15 +87,30 @@ impl SystemInformationDiagnosticsPlugin {
     not(feature = "bevy_dynamic_plugin")
 ))]
 pub mod internal {
-    use bevy_ecs::{prelude::ResMut, system::Local};
+    use bevy_ecs::{
+        prelude::{Res, ResMut},
+        system::Local,
+    };
     use bevy_log::info;
-    use sysinfo::{CpuExt, System, SystemExt};
+    use bevy_utils::Instant;
+    use sysinfo::{CpuExt, PidExt, ProcessExt, System, SystemExt};
 
     use crate::{Diagnostic, Diagnostics};
 
+    use super::SystemInfoConfig;
+
     const BYTES_TO_GIB: f64 = 1.0 / 1024.0 / 1024.0 / 1024.0;
 
-    pub(crate) fn setup_system(mut diagnostics: ResMut<Diagnostics>) {
+    /// `Local` state of `diagnostic_system`: the `sysinfo` handle, and when it was last refreshed.
+    struct SysinfoRefreshData {
+        sys: System,
+        last_refresh: Instant,
+    }
+
+    pub(crate) fn setup_system(
+        mut diagnostics: ResMut<Diagnostics>,
+        config: Res<SystemInfoConfig>,
+    ) {
         diagnostics.add(
             Diagnostic::new(
                 super::SystemInformationDiagnosticsPlugin::CPU_USAGE,
@@ -62,19 +127,87 @@ pub mod internal {
             )
             .with_suffix("%"),
         );
+        diagnostics.add(
+            Diagnostic::new(
+                super::SystemInformationDiagnosticsPlugin::PROCESS_CPU_USAGE,
+                "process_cpu_usage",
+                20,
+            )
+            .with_suffix("%"),
+        );
+        diagnostics.add(
+            Diagnostic::new(
+                super::SystemInformationDiagnosticsPlugin::PROCESS_MEM_USAGE,
+                "process_mem_usage",
+                20,
+            )
+            .with_suffix("GiB"),
+        );
+        diagnostics.add(
+            Diagnostic::new(
+                super::SystemInformationDiagnosticsPlugin::TOTAL_MEM,
+                "total_mem",
+                20,
+            )
+            .with_suffix("GiB"),
+        );
+        diagnostics.add(
+            Diagnostic::new(
+                super::SystemInformationDiagnosticsPlugin::USED_MEM,
+                "used_mem",
+                20,
+            )
+            .with_suffix("GiB"),
+        );
+
+        if config.per_core {
+            // Only need the CPU list here, not a full process/memory scan.
+            let mut sys = System::new();
+            sys.refresh_cpu();
+            for (index, _) in sys.cpus().iter().enumerate() {
+                // Leaked once at startup, one per logical CPU: `Diagnostic::new` wants a
+                // `&'static str` and the core count never changes at runtime.
+                let name: &'static str =
+                    Box::leak(format!("cpu_usage/core_{index}").into_boxed_str());
+                diagnostics.add(
+                    Diagnostic::new(
+                        super::SystemInformationDiagnosticsPlugin::core_cpu_usage_diagnostic_id(
+                            index,
+                        ),
+                        name,
+                        20,
+                    )
+                    .with_suffix("%"),
+                );
+            }
+        }
     }
 
     pub(crate) fn diagnostic_system(
         mut diagnostics: ResMut<Diagnostics>,
-        mut sysinfo: Local<Option<System>>,
+        config: Res<SystemInfoConfig>,
+        mut sysinfo: Local<Option<SysinfoRefreshData>>,
     ) {
         if sysinfo.is_none() {
-            *sysinfo = Some(System::new_all());
+            let now = Instant::now();
+            *sysinfo = Some(SysinfoRefreshData {
+                sys: System::new_all(),
+                // Already "due" so the very first call performs a real refresh.
+                last_refresh: now
+                    .checked_sub(config.refresh_interval)
+                    .unwrap_or(now),
+            });
         }
-        let Some(sys) = sysinfo.as_mut() else {
+        let Some(SysinfoRefreshData { sys, last_refresh }) = sysinfo.as_mut() else {
             return;
         };
 
+        let now = Instant::now();
+        if now.duration_since(*last_refresh) < config.refresh_interval {
+            return;
+        }
+        *last_refresh = now;
+
         sys.refresh_cpu();
         sys.refresh_memory();
         let current_cpu_usage = {
@@ -87,8 +220,8 @@ pub mod internal {
             usage / cpus.len() as f32
         };
         // `memory()` fns return a value in bytes
-        let total_mem = sys.total_memory() as f64 / BYTES_TO_GIB;
-        let used_mem = sys.used_memory() as f64 / BYTES_TO_GIB;
+        let total_mem = sys.total_memory() as f64 * BYTES_TO_GIB;
+        let used_mem = sys.used_memory() as f64 * BYTES_TO_GIB;
         let current_used_mem = used_mem / total_mem * 100.0;
 
         diagnostics.add_measurement(super::SystemInformationDiagnosticsPlugin::CPU_USAGE, || {
@@ -97,6 +230,42 @@ pub mod internal {
         diagnostics.add_measurement(super::SystemInformationDiagnosticsPlugin::MEM_USAGE, || {
             current_used_mem
         });
+        diagnostics.add_measurement(super::SystemInformationDiagnosticsPlugin::TOTAL_MEM, || {
+            total_mem
+        });
+        diagnostics.add_measurement(super::SystemInformationDiagnosticsPlugin::USED_MEM, || {
+            used_mem
+        });
+
+        if config.per_core {
+            for (index, cpu) in sys.cpus().iter().enumerate() {
+                let cpu_usage = cpu.cpu_usage();
+                diagnostics.add_measurement(
+                    super::SystemInformationDiagnosticsPlugin::core_cpu_usage_diagnostic_id(
+                        index,
+                    ),
+                    || cpu_usage as f64,
+                );
+            }
+        }
+
+        if let Ok(pid) = sysinfo::get_current_pid() {
+            sys.refresh_process(pid);
+            if let Some(process) = sys.process(pid) {
+                let current_process_cpu_usage = process.cpu_usage();
+                // Resident memory of this process, in GiB (mirrors `USED_MEM`/`TOTAL_MEM`).
+                let current_process_mem_usage = process.memory() as f64 * BYTES_TO_GIB;
+
+                diagnostics.add_measurement(
+                    super::SystemInformationDiagnosticsPlugin::PROCESS_CPU_USAGE,
+                    || current_process_cpu_usage as f64,
+                );
+                diagnostics.add_measurement(
+                    super::SystemInformationDiagnosticsPlugin::PROCESS_MEM_USAGE,
+                    || current_process_mem_usage,
+                );
+            }
+        }
     }
 
     #[derive(Debug)]